@@ -1,70 +1,93 @@
-use bincode::serialize;
-use serde::ser::Serialize;
-use std::cmp::PartialEq;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
 
 const INITIAL_CAPACITY: usize = 64;
 
-// The hash function takes a reference to a key and returns a usize
+// An opt-in BuildHasher that reproduces the table's original hashing
+// behavior, for callers who want a fixed-seed, allocation-free hash
+// instead of std's randomly-seeded SipHash in <RandomState>
 //
 // To calculate the hash we use the following steps:
-// 1. Convert the key to a byte array
+// 1. Feed the key's bytes to the hasher via `Hash::hash`
 // 2. Iterate over the bytes and calculate the hash with sdbm algorithm
 //      - http://www.cse.yorku.ca/~oz/hash.html
 // 3. Return the hash
-fn hash<K: Serialize>(key: &K) -> usize {
-    let bytes = serialize(key).unwrap();
-    let mut hash = 0;
-    for byte in bytes {
-        hash = (byte as usize)
-            .wrapping_add(hash << 6)
-            .wrapping_add(hash << 16)
-            .wrapping_sub(hash);
+#[derive(Debug, Clone, Default)]
+pub struct SdbmBuildHasher;
+
+impl BuildHasher for SdbmBuildHasher {
+    type Hasher = SdbmHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SdbmHasher::default()
+    }
+}
+
+// The Hasher half of <SdbmBuildHasher>
+#[derive(Debug, Default)]
+pub struct SdbmHasher(usize);
+
+impl Hasher for SdbmHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (byte as usize)
+                .wrapping_add(self.0 << 6)
+                .wrapping_add(self.0 << 16)
+                .wrapping_sub(self.0);
+        }
     }
-    hash
+
+    fn finish(&self) -> u64 {
+        self.0 as u64
+    }
+}
+
+// Hashes a key with the given hasher builder and returns the resulting
+// usize, ready to be reduced into a position with `% self.size()`
+fn hash<Key: Hash, S: BuildHasher>(key: &Key, hash_builder: &S) -> usize {
+    hash_builder.hash_one(key) as usize
 }
 
 // The HashElement struct needs to types parameters, Key and Value
 // The Key and Value types must implement the Default and Clone traits
+//
+// `dist` is the element's current displacement from its ideal bucket,
+// i.e. how many slots past `hash(key) % size` it had to probe before
+// finding room. Robin Hood hashing uses it both to decide who "has it
+// worse" during insertion and to stop a failed lookup early: no key can
+// ever sit further from home than the displacement already recorded in
+// the slot being examined
 #[derive(Debug, Clone)]
 struct HashElement<Key, Value> {
     key: Key,
     value: Value,
     default: bool,
-    deleted: bool,
+    dist: usize,
 }
 
-// The HashTable struct has two type parameters, Key and Value
+// The HashTable struct has three type parameters, Key, Value and S
 // The Key and Value types must implement the Default and Clone traits
 // The HashTable has a kvs field which is a Vec of tuples of Key and Value types
 // The HashTable has a len field which is the number of elements in the hash table
-// The HashTable has a size field which is the number of elements the hash table can hold without resizing
+// The HashTable has a hash_builder field which builds the <Hasher> used to hash keys
+//
+// S defaults to <RandomState>, the same hasher builder std's own HashMap
+// defaults to, so `HashTable<Key, Value>` keeps working without callers
+// having to name a hasher
 #[derive(Debug)]
-pub struct HashTable<Key, Value> {
+pub struct HashTable<Key, Value, S = RandomState> {
     kvs: Vec<HashElement<Key, Value>>,
     len: usize,
+    hash_builder: S,
 }
 
-// Implementation of the HashTable
-//
-// The Key and Value types must implement the Default and Clone traits
-// The HashTable has a kvs field which is a Vec of tuples of Key and Value types
-//
-// When a collision occurs we use Open Addressing with Linear Probing to handle it
-impl<Key: Default + Clone + Serialize + PartialEq, Value: Default + Clone> HashTable<Key, Value> {
+// Constructors that default to <RandomState>, mirroring the inherent
+// `HashMap::new`/`with_capacity` constructors in std, which are only
+// defined when the hasher builder is the default one
+impl<Key: Default + Clone + Hash + Eq, Value: Default + Clone> HashTable<Key, Value, RandomState> {
     // Returns a new HashTable with an initial capacity of <INITIAL_CAPACITY>
     pub fn new() -> Self {
-        Self {
-            kvs: vec![
-                HashElement {
-                    key: Key::default(),
-                    value: Value::default(),
-                    default: true,
-                    deleted: false,
-                };
-                INITIAL_CAPACITY
-            ],
-            len: 0,
-        }
+        Self::with_hasher(RandomState::new())
     }
 
     // Returns a new HashTable from a Vec of tuples
@@ -76,6 +99,68 @@ impl<Key: Default + Clone + Serialize + PartialEq, Value: Default + Clone> HashT
         hash_table
     }
 
+    // Returns a new HashTable with at least the given capacity
+    //
+    // The requested capacity is rounded up to the next power of two so the
+    // modulo-based indexing used by `hash` keeps distributing keys evenly,
+    // with a floor of <INITIAL_CAPACITY> so small requests still get a
+    // reasonable amount of room to grow into
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
+
+// Implementation of the HashTable
+//
+// The Key and Value types must implement the Default and Clone traits
+// The HashTable has a kvs field which is a Vec of tuples of Key and Value types
+//
+// When a collision occurs we use Open Addressing with Robin Hood hashing
+// to handle it, bounding probe-length variance by letting elements that
+// probed further from home "steal" a slot from elements that are closer
+// to theirs
+impl<Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher>
+    HashTable<Key, Value, S>
+{
+    // Returns a new HashTable with an initial capacity of <INITIAL_CAPACITY>,
+    // hashing keys with the given hasher builder
+    //
+    // This is how a caller plugs in a fixed-seed hasher (<SdbmBuildHasher>)
+    // for reproducible benchmarks, or a faster non-cryptographic one, in
+    // place of the default <RandomState>
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            kvs: vec![Self::empty_element(); INITIAL_CAPACITY],
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    // Returns a new HashTable with at least the given capacity, hashing
+    // keys with the given hasher builder
+    pub fn with_capacity_and_hasher(n: usize, hash_builder: S) -> Self {
+        let mut capacity = INITIAL_CAPACITY;
+        while capacity < n {
+            capacity *= 2;
+        }
+
+        Self {
+            kvs: vec![Self::empty_element(); capacity],
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    // Returns an empty slot to seed a freshly allocated backing vector
+    fn empty_element() -> HashElement<Key, Value> {
+        HashElement {
+            key: Key::default(),
+            value: Value::default(),
+            default: true,
+            dist: 0,
+        }
+    }
+
     // Returns if the Hash Table is empty
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -99,16 +184,40 @@ impl<Key: Default + Clone + Serialize + PartialEq, Value: Default + Clone> HashT
     }
 
     // The resize method doubles the size of the hash table when needed
+    //
+    // Growing the backing vector in place would leave every existing
+    // element at its old index, but `get`/`insert`/`remove` compute
+    // positions modulo the *current* size, so the positions of previously
+    // inserted keys would no longer match where they'd be probed. Instead
+    // we rebuild into a fresh vector of the new capacity and re-insert
+    // every live element, which recomputes each position against the new
+    // size
     fn resize(&mut self) {
-        self.kvs.resize(
-            self.size() * 2,
-            HashElement {
-                key: Key::default(),
-                value: Value::default(),
-                default: true,
-                deleted: false,
-            },
-        );
+        self.rebuild(self.size() * 2);
+    }
+
+    // Rebuilds the hash table into a fresh backing vector of the given
+    // capacity, re-inserting every live (non-default) element so
+    // positions and displacements are recomputed against the new size
+    fn rebuild(&mut self, capacity: usize) {
+        let old_kvs = std::mem::replace(&mut self.kvs, vec![Self::empty_element(); capacity]);
+        self.len = 0;
+
+        for element in old_kvs {
+            if !element.default {
+                self.insert(element.key, element.value);
+            }
+        }
+    }
+
+    // Rebuilds the hash table at its current size
+    //
+    // Robin Hood hashing's backward-shift deletion never leaves dead
+    // slots behind the way tombstone-based linear probing does, so this
+    // is mostly useful for restoring tightly-packed displacements after
+    // a run of inserts and removes has perturbed them
+    pub fn shrink_to_fit(&mut self) {
+        self.rebuild(self.size());
     }
 
     // The insert method takes a key and a value and inserts the key-value pair into the hash table
@@ -118,60 +227,75 @@ impl<Key: Default + Clone + Serialize + PartialEq, Value: Default + Clone> HashT
     //
     // If the hash table is at least at 70% capacity, the hash table is resized
     //
-    // The key-value pair is inserted using Open Addressing with Linear Probing
-    // If a collision occurs, the next available slot is used
-    // If the hash table is full, the hash table is resized
+    // Collisions are handled with Robin Hood hashing: the incoming
+    // element probes forward tracking its own displacement `dist` from
+    // its ideal bucket, and "steals from the rich" by swapping into any
+    // occupied slot whose current occupant has a smaller displacement,
+    // continuing the probe with the displaced element. This bounds how
+    // unlucky any single key's probe chain can get
     pub fn insert(&mut self, key: Key, value: Value) {
         // Check if we have to resize the vector of positions
         if self.load_factor() > 0.7 {
             self.resize()
         }
 
-        // Calculate the position
-        let hash = hash(&key);
-        let mut pos = hash % self.size();
+        let mut incoming = HashElement {
+            key,
+            value,
+            default: false,
+            dist: 0,
+        };
+        let mut pos = hash(&incoming.key, &self.hash_builder) % self.size();
+
+        loop {
+            if self.kvs[pos].default {
+                self.kvs[pos] = incoming;
+                self.len += 1;
+                return;
+            }
 
-        // Find the next available position
-        while !self.kvs[pos].default && !self.kvs[pos].deleted {
-            if self.kvs[pos].key == key {
-                self.kvs[pos].value = value;
+            if self.kvs[pos].key == incoming.key {
+                self.kvs[pos].value = incoming.value;
                 return;
             }
+
+            if self.kvs[pos].dist < incoming.dist {
+                std::mem::swap(&mut self.kvs[pos], &mut incoming);
+            }
+
             pos = (pos + 1) % self.size();
+            incoming.dist += 1;
         }
-
-        // Insert the key-value pair
-        self.kvs[pos] = HashElement {
-            key,
-            value,
-            default: false,
-            deleted: false,
-        };
-        self.len += 1;
     }
 
-    // The get method takes a key and returns its value in the hash table
-    //
-    // If the value exist it will return Some(value)
-    // Otherwise it will return None
-    //
-    // First it will calculate the hash of the key to get the position
+    // Probes for `key`, returning the slot it occupies
     //
-    pub fn get(&self, key: &Key) -> Option<&Value> {
-        // Calculate the position
-        let hash = hash(&key);
-        let mut pos = hash % self.size();
+    // Robin Hood hashing lets a failed lookup stop early: no key can sit
+    // further from its ideal bucket than the displacement already
+    // recorded in the slot currently being examined, so once the probe's
+    // own displacement exceeds that, `key` cannot be present
+    fn find(&self, key: &Key) -> Option<usize> {
+        let mut pos = hash(key, &self.hash_builder) % self.size();
+        let mut dist = 0;
 
-        // Find the element in the hash table
-        while !self.kvs[pos].default && !self.kvs[pos].deleted {
+        loop {
+            if self.kvs[pos].default || dist > self.kvs[pos].dist {
+                return None;
+            }
             if self.kvs[pos].key == *key {
-                return Some(&self.kvs[pos].value);
+                return Some(pos);
             }
             pos = (pos + 1) % self.size();
+            dist += 1;
         }
+    }
 
-        // The element does not exist
-        None
+    // The get method takes a key and returns its value in the hash table
+    //
+    // If the value exist it will return Some(value)
+    // Otherwise it will return None
+    pub fn get(&self, key: &Key) -> Option<&Value> {
+        self.find(key).map(|pos| &self.kvs[pos].value)
     }
 
     // The get_mut method takes a key and returns a mutable reference to its value in the hash table
@@ -179,53 +303,542 @@ impl<Key: Default + Clone + Serialize + PartialEq, Value: Default + Clone> HashT
     // If the value exist it will return Some(&mut value)
     // Otherwise it will return None
     pub fn get_mut(&mut self, key: &Key) -> Option<&mut Value> {
-        // Calculate the position
-        let hash = hash(&key);
-        let mut pos = hash % self.size();
-
-        // Find the element in the hash table
-        while !self.kvs[pos].default && !self.kvs[pos].deleted {
-            if self.kvs[pos].key == *key {
-                return Some(&mut self.kvs[pos].value);
-            }
-            pos = (pos + 1) % self.size();
-        }
-
-        // The element does not exist
-        None
+        self.find(key).map(|pos| &mut self.kvs[pos].value)
     }
 
     // The remove method takes a key and removes its value from the hash table
     //
-    // Instead of removing the element, we mark it as deleted
-    // This is because we are using Open Addressing with Linear Probing
-    // If we remove the element, we will not be able to find the next element in the sequence
-    //
     // If the value exist it will return Some(value)
     // Otherwise it will return None
+    //
+    // Removal uses backward-shift deletion instead of tombstoning: once
+    // the element is cleared, each following slot is shifted back one
+    // position (with its displacement decremented to match) until an
+    // empty slot or a slot already at displacement 0 is reached, since
+    // neither of those could have been displaced by the removed element.
+    // This keeps probe chains short without ever accumulating dead slots
     pub fn remove(&mut self, key: Key) -> Option<Value> {
-        // Calculate the position
-        let hash = hash(&key);
-        let mut pos = hash % self.size();
-
-        // Find the element in the hash table
-        while pos < self.size() && !self.kvs[pos].default && !self.kvs[pos].deleted {
-            if self.kvs[pos].key == key {
-                self.kvs[pos].deleted = true;
-                self.len -= 1;
-                return Some(self.kvs[pos].value.clone());
+        let pos = self.find(&key)?;
+        let value = self.kvs[pos].value.clone();
+        self.backward_shift(pos);
+        self.len -= 1;
+        Some(value)
+    }
+
+    // Shifts every element following `pos` back by one slot until an
+    // empty slot or a slot at displacement 0 is reached, then clears
+    // the last slot the shift vacated
+    fn backward_shift(&mut self, mut pos: usize) {
+        loop {
+            let next = (pos + 1) % self.size();
+            if self.kvs[next].default || self.kvs[next].dist == 0 {
+                self.kvs[pos] = Self::empty_element();
+                return;
+            }
+            self.kvs[pos] = self.kvs[next].clone();
+            self.kvs[pos].dist -= 1;
+            pos = next;
+        }
+    }
+
+    // The entry method returns a view into a single slot of the hash
+    // table, which may be either occupied or vacant, for in-place
+    // read-modify-write access without a separate `get_mut` + `insert`
+    //
+    // Like `insert`, it resizes the table first if the load factor is
+    // too high, since a resize may change which slot the key belongs in
+    pub fn entry(&mut self, key: Key) -> Entry<'_, Key, Value, S> {
+        if self.load_factor() > 0.7 {
+            self.resize();
+        }
+
+        match self.find(&key) {
+            Some(pos) => Entry::Occupied(OccupiedEntry { table: self, pos }),
+            None => Entry::Vacant(VacantEntry { table: self, key }),
+        }
+    }
+
+    // Returns an iterator over `(&Key, &Value)` pairs for every live
+    // element, skipping empty slots
+    pub fn iter(&self) -> Iter<'_, Key, Value> {
+        Iter {
+            inner: self.kvs.iter(),
+        }
+    }
+
+    // Returns an iterator over `(&Key, &mut Value)` pairs for every live
+    // element, skipping empty slots
+    pub fn iter_mut(&mut self) -> IterMut<'_, Key, Value> {
+        IterMut {
+            inner: self.kvs.iter_mut(),
+        }
+    }
+
+    // Returns an iterator over references to the keys of every live
+    // element
+    pub fn keys(&self) -> Keys<'_, Key, Value> {
+        Keys { inner: self.iter() }
+    }
+
+    // Returns an iterator over references to the values of every live
+    // element
+    pub fn values(&self) -> Values<'_, Key, Value> {
+        Values { inner: self.iter() }
+    }
+
+    // Returns an iterator over mutable references to the values of
+    // every live element
+    pub fn values_mut(&mut self) -> ValuesMut<'_, Key, Value> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+}
+
+impl<Key: Default + Clone + Hash + Eq, Value: Default + Clone> Default
+    for HashTable<Key, Value, RandomState>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// An iterator over `(&Key, &Value)` pairs, returned by `HashTable::iter`
+pub struct Iter<'a, Key, Value> {
+    inner: std::slice::Iter<'a, HashElement<Key, Value>>,
+}
+
+impl<'a, Key, Value> Iterator for Iter<'a, Key, Value> {
+    type Item = (&'a Key, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for element in self.inner.by_ref() {
+            if !element.default {
+                return Some((&element.key, &element.value));
+            }
+        }
+        None
+    }
+}
+
+// An iterator over `(&Key, &mut Value)` pairs, returned by
+// `HashTable::iter_mut`
+pub struct IterMut<'a, Key, Value> {
+    inner: std::slice::IterMut<'a, HashElement<Key, Value>>,
+}
+
+impl<'a, Key, Value> Iterator for IterMut<'a, Key, Value> {
+    type Item = (&'a Key, &'a mut Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for element in self.inner.by_ref() {
+            if !element.default {
+                return Some((&element.key, &mut element.value));
             }
-            pos = (pos + 1) % self.size();
         }
+        None
+    }
+}
+
+// A consuming iterator over `(Key, Value)` pairs, returned by
+// `HashTable::into_iter`
+pub struct IntoIter<Key, Value> {
+    inner: std::vec::IntoIter<HashElement<Key, Value>>,
+}
+
+impl<Key, Value> Iterator for IntoIter<Key, Value> {
+    type Item = (Key, Value);
 
-        // The element does not exist
+    fn next(&mut self) -> Option<Self::Item> {
+        for element in self.inner.by_ref() {
+            if !element.default {
+                return Some((element.key, element.value));
+            }
+        }
         None
     }
 }
 
-impl<Key: Default + Clone + Serialize + PartialEq, Value: Default + Clone> Default
-    for HashTable<Key, Value>
+impl<Key, Value, S> IntoIterator for HashTable<Key, Value, S> {
+    type Item = (Key, Value);
+    type IntoIter = IntoIter<Key, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.kvs.into_iter(),
+        }
+    }
+}
+
+impl<'a, Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher> IntoIterator
+    for &'a HashTable<Key, Value, S>
+{
+    type Item = (&'a Key, &'a Value);
+    type IntoIter = Iter<'a, Key, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher> IntoIterator
+    for &'a mut HashTable<Key, Value, S>
+{
+    type Item = (&'a Key, &'a mut Value);
+    type IntoIter = IterMut<'a, Key, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// An iterator over references to the keys of every live element,
+// returned by `HashTable::keys`
+pub struct Keys<'a, Key, Value> {
+    inner: Iter<'a, Key, Value>,
+}
+
+impl<'a, Key, Value> Iterator for Keys<'a, Key, Value> {
+    type Item = &'a Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+}
+
+// An iterator over references to the values of every live element,
+// returned by `HashTable::values`
+pub struct Values<'a, Key, Value> {
+    inner: Iter<'a, Key, Value>,
+}
+
+impl<'a, Key, Value> Iterator for Values<'a, Key, Value> {
+    type Item = &'a Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+// An iterator over mutable references to the values of every live
+// element, returned by `HashTable::values_mut`
+pub struct ValuesMut<'a, Key, Value> {
+    inner: IterMut<'a, Key, Value>,
+}
+
+impl<'a, Key, Value> Iterator for ValuesMut<'a, Key, Value> {
+    type Item = &'a mut Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+// Building a HashTable from an iterator of pairs, generalizing the
+// existing `HashTable::from(Vec<...>)` constructor to any
+// `IntoIterator`, the same way std's `HashMap` implements
+// `FromIterator` for any hasher builder that is also `Default`
+impl<Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher + Default>
+    FromIterator<(Key, Value)> for HashTable<Key, Value, S>
+{
+    fn from_iter<I: IntoIterator<Item = (Key, Value)>>(iter: I) -> Self {
+        let mut hash_table = HashTable::with_hasher(S::default());
+        hash_table.extend(iter);
+        hash_table
+    }
+}
+
+// Bulk-inserting pairs from an iterator into an existing HashTable
+impl<Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher>
+    Extend<(Key, Value)> for HashTable<Key, Value, S>
+{
+    fn extend<I: IntoIterator<Item = (Key, Value)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+// A view into a single slot of a <HashTable>, obtained from `entry`
+//
+// See the `or_insert`/`or_insert_with`/`or_default`/`and_modify` methods
+// below for the usual ways to consume it
+pub enum Entry<'a, Key, Value, S> {
+    Occupied(OccupiedEntry<'a, Key, Value, S>),
+    Vacant(VacantEntry<'a, Key, Value, S>),
+}
+
+impl<'a, Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher>
+    Entry<'a, Key, Value, S>
+{
+    // Ensures the entry has a value, inserting `default` if it is vacant,
+    // and returns a mutable reference to the value
+    pub fn or_insert(self, default: Value) -> &'a mut Value {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    // Ensures the entry has a value, inserting the result of `default` if
+    // it is vacant, and returns a mutable reference to the value
+    pub fn or_insert_with<F: FnOnce() -> Value>(self, default: F) -> &'a mut Value {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    // Ensures the entry has a value, inserting `Value::default()` if it
+    // is vacant, and returns a mutable reference to the value
+    pub fn or_default(self) -> &'a mut Value {
+        self.or_insert_with(Value::default)
+    }
+
+    // Applies `f` to the value if the entry is occupied, leaving a
+    // vacant entry untouched, and returns the entry so it can still be
+    // chained into `or_insert`/`or_insert_with`/`or_default`
+    pub fn and_modify<F: FnOnce(&mut Value)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+// An occupied slot returned by `entry`, holding the index of the
+// matching element
+pub struct OccupiedEntry<'a, Key, Value, S> {
+    table: &'a mut HashTable<Key, Value, S>,
+    pos: usize,
+}
+
+impl<'a, Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher>
+    OccupiedEntry<'a, Key, Value, S>
+{
+    // Returns a reference to the entry's key
+    pub fn key(&self) -> &Key {
+        &self.table.kvs[self.pos].key
+    }
+
+    // Returns a reference to the entry's value
+    pub fn get(&self) -> &Value {
+        &self.table.kvs[self.pos].value
+    }
+
+    // Returns a mutable reference to the entry's value
+    pub fn get_mut(&mut self) -> &mut Value {
+        &mut self.table.kvs[self.pos].value
+    }
+
+    // Consumes the entry, returning a mutable reference to the value
+    // that outlives the entry itself
+    pub fn into_mut(self) -> &'a mut Value {
+        &mut self.table.kvs[self.pos].value
+    }
+
+    // Replaces the entry's value, returning the old one
+    pub fn insert(&mut self, value: Value) -> Value {
+        std::mem::replace(&mut self.table.kvs[self.pos].value, value)
+    }
+}
+
+// A vacant slot returned by `entry`, holding the key that was looked up
+//
+// Unlike the old tombstone scheme, Robin Hood insertion can relocate
+// several existing elements while placing a new one, so the key's final
+// resting slot isn't known until `insert` actually runs it through the
+// same probe-and-steal-from-the-rich sequence `HashTable::insert` uses
+pub struct VacantEntry<'a, Key, Value, S> {
+    table: &'a mut HashTable<Key, Value, S>,
+    key: Key,
+}
+
+impl<'a, Key: Default + Clone + Hash + Eq, Value: Default + Clone, S: BuildHasher>
+    VacantEntry<'a, Key, Value, S>
 {
+    // Returns a reference to the entry's key
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    // Inserts the value, returning a mutable reference to it
+    pub fn insert(self, value: Value) -> &'a mut Value {
+        self.table.insert(self.key.clone(), value);
+        self.table.get_mut(&self.key).expect("just inserted")
+    }
+}
+
+// An alternative collision strategy to the Robin Hood open addressing
+// used by <HashTable>: each bucket holds a small `Vec` of `(Key, Value)`
+// pairs instead of every key competing for a single backing slot
+//
+// Separate chaining tolerates load factors above 1.0 and degrades
+// gracefully for adversarial key distributions that would cluster
+// badly under open addressing, at the cost of an extra allocation per
+// bucket and worse cache locality. It shares `HashTable`'s public
+// insert/get/get_mut/remove/len surface so the two strategies are
+// interchangeable for callers who want to pick one. Its `load_factor`
+// is public, while `HashTable::load_factor` is private
+#[derive(Debug)]
+pub struct ChainedHashTable<Key, Value, S = RandomState> {
+    buckets: Vec<Vec<(Key, Value)>>,
+    len: usize,
+    hash_builder: S,
+}
+
+// Constructors that default to <RandomState>, mirroring the
+// <HashTable>/std `HashMap` split between hasher-agnostic constructors
+// and the ones that are only defined for the default hasher builder
+impl<Key: Clone + Hash + Eq, Value: Clone> ChainedHashTable<Key, Value, RandomState> {
+    // Returns a new ChainedHashTable with an initial bucket count of
+    // <INITIAL_CAPACITY>
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    // Returns a new ChainedHashTable from a Vec of tuples
+    pub fn from(v: Vec<(Key, Value)>) -> Self {
+        let mut hash_table = ChainedHashTable::new();
+        for (key, value) in v {
+            hash_table.insert(key, value);
+        }
+        hash_table
+    }
+
+    // Returns a new ChainedHashTable with at least the given number of
+    // buckets, rounded up to the next power of two
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
+
+impl<Key: Clone + Hash + Eq, Value: Clone, S: BuildHasher> ChainedHashTable<Key, Value, S> {
+    // Returns a new ChainedHashTable with an initial bucket count of
+    // <INITIAL_CAPACITY>, hashing keys with the given hasher builder
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            buckets: (0..INITIAL_CAPACITY).map(|_| Vec::new()).collect(),
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    // Returns a new ChainedHashTable with at least the given number of
+    // buckets, hashing keys with the given hasher builder
+    pub fn with_capacity_and_hasher(n: usize, hash_builder: S) -> Self {
+        let mut capacity = INITIAL_CAPACITY;
+        while capacity < n {
+            capacity *= 2;
+        }
+
+        Self {
+            buckets: (0..capacity).map(|_| Vec::new()).collect(),
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    // Returns if the ChainedHashTable is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns the number of elements in the hash table
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns the number of buckets the hash table currently has
+    pub fn size(&self) -> usize {
+        self.buckets.len()
+    }
+
+    // Calculates the load factor of the hash table with the formula:
+    // load factor = number of elements / number of buckets
+    //
+    // Unlike open addressing, correctness doesn't depend on this staying
+    // below 1.0, since a bucket just grows its `Vec` instead of running
+    // out of room; `insert` still doubles the bucket count once it
+    // passes 0.7
+    pub fn load_factor(&self) -> f64 {
+        self.len as f64 / self.size() as f64
+    }
+
+    // Returns which bucket a key belongs in
+    fn bucket_index(&self, key: &Key) -> usize {
+        hash(key, &self.hash_builder) % self.size()
+    }
+
+    // Doubles the bucket count and rehashes every entry into the new
+    // bucket array, at the same 0.7 load-factor threshold <HashTable>
+    // uses to trigger its own resize
+    fn resize(&mut self) {
+        let new_size = self.size() * 2;
+        let old_buckets =
+            std::mem::replace(&mut self.buckets, (0..new_size).map(|_| Vec::new()).collect());
+        self.len = 0;
+
+        for bucket in old_buckets {
+            for (key, value) in bucket {
+                self.insert(key, value);
+            }
+        }
+    }
+
+    // Inserts a key-value pair, updating the value in place if the key
+    // is already present in its bucket
+    pub fn insert(&mut self, key: Key, value: Value) {
+        if self.load_factor() > 0.7 {
+            self.resize();
+        }
+
+        let index = self.bucket_index(&key);
+        let bucket = &mut self.buckets[index];
+        for entry in bucket.iter_mut() {
+            if entry.0 == key {
+                entry.1 = value;
+                return;
+            }
+        }
+
+        bucket.push((key, value));
+        self.len += 1;
+    }
+
+    // Returns a reference to the value for `key`, scanning its bucket
+    pub fn get(&self, key: &Key) -> Option<&Value> {
+        self.buckets[self.bucket_index(key)]
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    // Returns a mutable reference to the value for `key`, scanning its
+    // bucket
+    pub fn get_mut(&mut self, key: &Key) -> Option<&mut Value> {
+        let index = self.bucket_index(key);
+        self.buckets[index]
+            .iter_mut()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    // Removes and returns the value for `key`
+    //
+    // Unlike <HashTable>, there's no probe chain to preserve, so no
+    // following entries need shifting back: the entry is just dropped
+    // out of its bucket's `Vec`
+    pub fn remove(&mut self, key: Key) -> Option<Value> {
+        let index = self.bucket_index(&key);
+        let bucket = &mut self.buckets[index];
+        let pos = bucket.iter().position(|(k, _)| *k == key)?;
+        self.len -= 1;
+        Some(bucket.remove(pos).1)
+    }
+}
+
+impl<Key: Clone + Hash + Eq, Value: Clone> Default for ChainedHashTable<Key, Value, RandomState> {
     fn default() -> Self {
         Self::new()
     }
@@ -365,6 +978,124 @@ mod tests {
         assert_eq!(hash_table.len(), 1);
     }
 
+    #[test]
+    fn test_remove_shifts_following_elements_back() {
+        // Under the fixed-seed sdbm hasher, 0, 64, 128 and 192 all hash to
+        // slot 0 of a 64-slot table, so they form a single probe chain and
+        // removing 0 has to shift each of the others back one slot
+        let mut hash_table: HashTable<i32, i32, SdbmBuildHasher> =
+            HashTable::with_hasher(SdbmBuildHasher);
+        for i in 0..4 {
+            hash_table.insert(i * 64, i);
+        }
+        hash_table.remove(0);
+        assert_eq!(hash_table.len(), 3);
+        for i in 1..4 {
+            assert_eq!(hash_table.get(&(i * 64)), Some(&i));
+        }
+        assert_eq!(hash_table.get(&0), None);
+        assert_eq!(hash_table.kvs[0].key, 64);
+        assert_eq!(hash_table.kvs[0].dist, 0);
+    }
+
+    #[test]
+    fn test_with_capacity_rounds_up() {
+        let hash_table: HashTable<i32, i32> = HashTable::with_capacity(100);
+        assert_eq!(hash_table.size(), 128);
+    }
+
+    #[test]
+    fn test_with_capacity_floor() {
+        let hash_table: HashTable<i32, i32> = HashTable::with_capacity(1);
+        assert_eq!(hash_table.size(), INITIAL_CAPACITY);
+    }
+
+    #[test]
+    fn test_lookups_survive_resize() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        for i in 0..(INITIAL_CAPACITY + 1) as i32 {
+            hash_table.insert(i, i * 2);
+        }
+        for i in 0..(INITIAL_CAPACITY + 1) as i32 {
+            assert_eq!(hash_table.get(&i), Some(&(i * 2)));
+        }
+    }
+
+    #[test]
+    fn test_shrink_to_fit_keeps_elements_and_size() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        for i in 0..10 {
+            hash_table.insert(i, i);
+        }
+        for i in 0..5 {
+            hash_table.remove(i);
+        }
+        let size_before = hash_table.size();
+        hash_table.shrink_to_fit();
+        assert_eq!(hash_table.size(), size_before);
+        assert_eq!(hash_table.len(), 5);
+        for i in 5..10 {
+            assert_eq!(hash_table.get(&i), Some(&i));
+        }
+    }
+
+    #[test]
+    fn test_entry_or_insert_on_vacant() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        *hash_table.entry(0).or_insert(5) += 1;
+        assert_eq!(hash_table.get(&0), Some(&6));
+        assert_eq!(hash_table.len(), 1);
+    }
+
+    #[test]
+    fn test_entry_or_insert_on_occupied() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.insert(0, 1);
+        *hash_table.entry(0).or_insert(5) += 1;
+        assert_eq!(hash_table.get(&0), Some(&2));
+        assert_eq!(hash_table.len(), 1);
+    }
+
+    #[test]
+    fn test_entry_or_insert_with() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.entry(0).or_insert_with(|| 42);
+        assert_eq!(hash_table.get(&0), Some(&42));
+    }
+
+    #[test]
+    fn test_entry_or_default() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.entry(0).or_default();
+        assert_eq!(hash_table.get(&0), Some(&0));
+    }
+
+    #[test]
+    fn test_entry_and_modify_on_occupied() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.insert(0, 1);
+        hash_table.entry(0).and_modify(|v| *v += 1).or_insert(100);
+        assert_eq!(hash_table.get(&0), Some(&2));
+    }
+
+    #[test]
+    fn test_entry_and_modify_on_vacant_falls_through_to_or_insert() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.entry(0).and_modify(|v| *v += 1).or_insert(100);
+        assert_eq!(hash_table.get(&0), Some(&100));
+    }
+
+    #[test]
+    fn test_entry_after_remove_does_not_resize() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.insert(0, 0);
+        hash_table.remove(0);
+        let size_before = hash_table.size();
+        hash_table.entry(0).or_insert(1);
+        assert_eq!(hash_table.size(), size_before);
+        assert_eq!(hash_table.get(&0), Some(&1));
+    }
+
     #[test]
     fn test_hash_table_from_vector() {
         let hash_table = HashTable::from(vec![(0, 0), (1, 1), (2, 2)]);
@@ -374,4 +1105,145 @@ mod tests {
         assert_eq!(hash_table.get(&3), None);
         assert_eq!(hash_table.len(), 3);
     }
+
+    #[test]
+    fn test_iter_visits_every_element_once() {
+        let hash_table = HashTable::from(vec![(0, 1), (1, 2), (2, 3)]);
+        let mut pairs: Vec<(i32, i32)> = hash_table.iter().map(|(k, v)| (*k, *v)).collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_iter_mut_can_update_values() {
+        let mut hash_table = HashTable::from(vec![(0, 1), (1, 2)]);
+        for (_, value) in hash_table.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(hash_table.get(&0), Some(&10));
+        assert_eq!(hash_table.get(&1), Some(&20));
+    }
+
+    #[test]
+    fn test_into_iter_consumes_table() {
+        let hash_table = HashTable::from(vec![(0, 1), (1, 2)]);
+        let mut pairs: Vec<(i32, i32)> = hash_table.into_iter().collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_keys_and_values() {
+        let hash_table = HashTable::from(vec![(0, 1), (1, 2)]);
+        let mut keys: Vec<i32> = hash_table.keys().copied().collect();
+        let mut values: Vec<i32> = hash_table.values().copied().collect();
+        keys.sort();
+        values.sort();
+        assert_eq!(keys, vec![0, 1]);
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_from_iterator_collect() {
+        let hash_table: HashTable<i32, i32> = vec![(0, 1), (1, 2), (2, 3)].into_iter().collect();
+        assert_eq!(hash_table.get(&0), Some(&1));
+        assert_eq!(hash_table.get(&1), Some(&2));
+        assert_eq!(hash_table.get(&2), Some(&3));
+        assert_eq!(hash_table.len(), 3);
+    }
+
+    #[test]
+    fn test_extend_bulk_inserts() {
+        let mut hash_table = HashTable::from(vec![(0, 1)]);
+        hash_table.extend(vec![(1, 2), (2, 3)]);
+        assert_eq!(hash_table.len(), 3);
+        assert_eq!(hash_table.get(&1), Some(&2));
+        assert_eq!(hash_table.get(&2), Some(&3));
+    }
+
+    #[test]
+    fn test_with_sdbm_hasher_is_deterministic() {
+        let mut a: HashTable<i32, i32, SdbmBuildHasher> =
+            HashTable::with_hasher(SdbmBuildHasher);
+        let mut b: HashTable<i32, i32, SdbmBuildHasher> =
+            HashTable::with_hasher(SdbmBuildHasher);
+        for i in 0..20 {
+            a.insert(i, i * 2);
+            b.insert(i, i * 2);
+        }
+        for i in 0..20 {
+            assert_eq!(a.get(&i), b.get(&i));
+        }
+        assert!(a.keys().eq(b.keys()));
+    }
+
+    #[test]
+    fn test_chained_create_hash_table() {
+        let hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        assert_eq!(hash_table.size(), INITIAL_CAPACITY);
+        assert!(hash_table.is_empty());
+    }
+
+    #[test]
+    fn test_chained_insert_and_get() {
+        let mut hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        hash_table.insert(0, 1);
+        assert_eq!(hash_table.get(&0), Some(&1));
+        assert_eq!(hash_table.len(), 1);
+    }
+
+    #[test]
+    fn test_chained_insert_updates_existing_key() {
+        let mut hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        hash_table.insert(0, 1);
+        hash_table.insert(0, 2);
+        assert_eq!(hash_table.get(&0), Some(&2));
+        assert_eq!(hash_table.len(), 1);
+    }
+
+    #[test]
+    fn test_chained_get_mut() {
+        let mut hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        hash_table.insert(0, 1);
+        *hash_table.get_mut(&0).unwrap() = 2;
+        assert_eq!(hash_table.get(&0), Some(&2));
+    }
+
+    #[test]
+    fn test_chained_remove() {
+        let mut hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        hash_table.insert(0, 1);
+        assert_eq!(hash_table.remove(0), Some(1));
+        assert_eq!(hash_table.get(&0), None);
+        assert_eq!(hash_table.len(), 0);
+    }
+
+    #[test]
+    fn test_chained_remove_when_key_does_not_exist() {
+        let mut hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        assert_eq!(hash_table.remove(0), None);
+    }
+
+    #[test]
+    fn test_chained_resize_keeps_elements_reachable() {
+        let mut hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+        for i in 0..(INITIAL_CAPACITY + 1) as i32 {
+            hash_table.insert(i, i * 2);
+        }
+        assert_eq!(hash_table.len(), INITIAL_CAPACITY + 1);
+        assert!(hash_table.size() > INITIAL_CAPACITY);
+        for i in 0..(INITIAL_CAPACITY + 1) as i32 {
+            assert_eq!(hash_table.get(&i), Some(&(i * 2)));
+        }
+    }
+
+    #[test]
+    fn test_chained_hash_table_from_vector() {
+        let hash_table = ChainedHashTable::from(vec![(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(hash_table.get(&0), Some(&0));
+        assert_eq!(hash_table.get(&1), Some(&1));
+        assert_eq!(hash_table.get(&2), Some(&2));
+        assert_eq!(hash_table.get(&3), None);
+        assert_eq!(hash_table.len(), 3);
+    }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use rust_hash_table::hash_table::HashTable;
+use rust_hash_table::hash_table::{ChainedHashTable, HashTable};
 
 const ITERATIONS: i32 = 1e7 as i32;
 
@@ -10,7 +10,14 @@ fn main() {
     for i in 0..ITERATIONS {
         hash_table.insert(i, 0);
     }
-    println!("My implementation time: {:?}", start.elapsed());
+    println!("My implementation time (open addressing): {:?}", start.elapsed());
+
+    let start = std::time::Instant::now();
+    let mut chained_hash_table: ChainedHashTable<i32, i32> = ChainedHashTable::new();
+    for i in 0..ITERATIONS {
+        chained_hash_table.insert(i, 0);
+    }
+    println!("My implementation time (separate chaining): {:?}", start.elapsed());
 
     let start = std::time::Instant::now();
     let mut hash_map: HashMap<i32, i32> = HashMap::new();